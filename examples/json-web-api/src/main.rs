@@ -3,11 +3,14 @@ extern crate rocket;
 #[macro_use]
 extern crate rocket_okapi;
 
+use okapi::openapi3::OpenApi;
 use rocket::form::FromForm;
 use rocket::serde::json::Json;
+use rocket_okapi::handlers::ContentHandler;
 use rocket_okapi::swagger_ui::*;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use serde_json::json;
 
 #[derive(Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
@@ -93,15 +96,41 @@ fn create_post_by_query(post: Post) -> Option<Json<Post>> {
     Some(Json(post))
 }
 
+/// A minimal OpenAPI document for a retired API version, bundled with the Swagger UI itself
+/// (via [UrlObject::with_spec]) instead of requiring its own route mount.
+fn retired_v0_spec() -> OpenApi {
+    serde_json::from_value(json!({
+        "openapi": "3.0.0",
+        "info": { "title": "JSON Web API", "version": "0.1.0" },
+        "paths": {},
+    }))
+    .expect("retired_v0_spec is a valid OpenApi document")
+}
+
+/// A `swagger-ui.css` override, registered through [SwaggerUIExtraFiles] so it's served
+/// alongside the bundled Swagger UI assets without having to patch them.
+const CUSTOM_SWAGGER_CSS: &[u8] = b".swagger-ui .topbar { background-color: #2c3e50; }\n";
+
+fn extra_files() -> SwaggerUIExtraFiles {
+    SwaggerUIExtraFiles::new().with(
+        "swagger-ui.css",
+        ContentHandler::spec("swagger-ui.css", CUSTOM_SWAGGER_CSS.to_vec()),
+    )
+}
+
 #[rocket::launch]
 fn rocket() -> _ {
     let swagger_ui_config = SwaggerUIConfig {
-        url: "../openapi.json".to_owned(),
+        urls: vec![
+            UrlObject::new("current", "/openapi.json"),
+            UrlObject::with_spec("v0 (retired)", "openapi-v0.json", retired_v0_spec()),
+        ],
         ..Default::default()
     };
 
     rocket::build()
         .attach(swagger_ui_config.fairing())
+        .attach(extra_files().fairing())
         .mount(
             "/",
             routes_with_openapi![
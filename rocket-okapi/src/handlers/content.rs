@@ -0,0 +1,42 @@
+use rocket::http::ContentType;
+use rocket::response::{self, Responder};
+use rocket::Request;
+
+/// A handler that serves a fixed blob of bytes with a given [ContentType], used to serve extra
+/// spec or asset files alongside the bundled Swagger UI/RapiDoc files.
+#[derive(Debug, Clone)]
+pub struct ContentHandler {
+    content_type: ContentType,
+    data: Vec<u8>,
+}
+
+impl ContentHandler {
+    /// Create a new `ContentHandler` serving `data` with the given `content_type`.
+    #[must_use]
+    pub fn bytes(content_type: ContentType, data: Vec<u8>) -> Self {
+        Self { content_type, data }
+    }
+
+    /// Create a new `ContentHandler` serving `data` as a file named `name`, inferring the
+    /// [ContentType] from its extension (`.json`, `.yaml`/`.yml`, `.html`, `.js`, `.css`,
+    /// `.png`), and defaulting to plain text for anything else.
+    #[must_use]
+    pub fn spec(name: &str, data: Vec<u8>) -> Self {
+        let content_type = match name.rsplit('.').next().unwrap_or_default() {
+            "json" => ContentType::JSON,
+            "yaml" | "yml" => ContentType::new("application", "yaml"),
+            "html" => ContentType::HTML,
+            "js" => ContentType::JavaScript,
+            "css" => ContentType::CSS,
+            "png" => ContentType::PNG,
+            _ => ContentType::Plain,
+        };
+        Self::bytes(content_type, data)
+    }
+}
+
+impl<'r, 'o: 'r> Responder<'r, 'o> for ContentHandler {
+    fn respond_to(self, request: &'r Request<'_>) -> response::Result<'o> {
+        (self.content_type, self.data).respond_to(request)
+    }
+}
@@ -0,0 +1,5 @@
+mod content;
+mod redirect;
+
+pub use content::ContentHandler;
+pub use redirect::RedirectHandler;
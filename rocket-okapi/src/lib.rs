@@ -13,8 +13,6 @@
 //! serde = "1.0"
 //! okapi = { version = "0.6.1", package = "okapi_fork" }
 //! rocket_okapi = { version = "0.8.0-rc.1", package = "rocket_okapi_fork" }
-//! ## Add rocket_okapi_ui if you want do embedd Swagger UI
-//! rocket_okapi_ui = "0.1.0-rc.1"
 //! ```
 //!
 //! To add documentation to a set of endpoints, a couple of steps are required:
@@ -26,11 +24,14 @@
 //!   resulting [Vec]<[Route](rocket::Route)>, which contains the `openapi.json`
 //!   file that is required by swagger.
 //!
-//! To serve [Swagger UI](https://swagger.io/tools/swagger-ui/) directly from
-//! your Rocket application additional steps are required:
-//! - Add the `rocket_okapi_ui` dependency to your `Cargo.toml`
-//! - Attach the [SwaggerUIConfig](rocket_okapi_ui::SwaggerUIConfig) fairing to Rocket.
-//! - Mount the Swagger UI routes created with [swagger_ui_routes![]](rocket_okapi_ui::swagger_ui_routes!).
+//! To serve [Swagger UI](https://swagger.io/tools/swagger-ui/) directly from your Rocket
+//! application, no extra dependency is needed:
+//! - Attach the [SwaggerUIConfig](swagger_ui::SwaggerUIConfig) fairing to Rocket.
+//! - Mount the Swagger UI routes created with [swagger_ui_routes![]](swagger_ui_routes!).
+//!
+//! [RapiDoc](https://github.com/rapi-doc/RapiDoc) is available the same way, through
+//! [RapiDocConfig](rapidoc::RapiDocConfig) and [rapidoc_routes![]](rapidoc_routes!), if you'd
+//! rather use a lighter-weight documentation front-end.
 //!
 //! Now you should be able to load the example in the browser!
 //!
@@ -38,11 +39,10 @@
 //! ```rust
 //! #[macro_use] extern crate rocket;
 //! #[macro_use] extern crate rocket_okapi;
-//! #[macro_use] extern crate rocket_okapi_ui;
 //!
 //! use rocket::serde::json::Json;
 //! use rocket_okapi::JsonSchema;
-//! use rocket_okapi_ui::{SwaggerUIConfig, UrlObject};
+//! use rocket_okapi::swagger_ui::{SwaggerUIConfig, UrlObject};
 //! use serde::Serialize;
 //!
 //! #[derive(Serialize, JsonSchema)]
@@ -82,6 +82,9 @@ pub mod gen;
 /// Contains several `Rocket` `Handler`s, which are used for serving the json files and the swagger
 /// interface.
 pub mod handlers;
+/// Contains the `RapiDocConfig` struct and routes needed to embed a
+/// [RapiDoc](https://github.com/rapi-doc/RapiDoc) documentation front-end.
+pub mod rapidoc;
 /// This module contains several traits that correspond to the `Rocket` traits pertaining to request
 /// guards and responses
 pub mod request;
@@ -91,6 +94,9 @@ pub mod response;
 /// Contains then `OpenApiSettings` struct, which can be used to customise the behaviour of a
 /// `Generator`.
 pub mod settings;
+/// Contains the `SwaggerUIConfig` struct and routes needed to embed a Swagger UI documentation
+/// front-end.
+pub mod swagger_ui;
 /// Assorted function that are used throughout the application.
 pub mod util;
 
@@ -0,0 +1,300 @@
+use crate::swagger_ui::UrlObject;
+use serde::{Deserialize, Serialize};
+
+/// Controls which specs RapiDoc knows about and some general page chrome.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeneralConfig {
+    /// A list of named urls that contain the `openapi.json` files you want RapiDoc to be able to
+    /// serve. Unlike Swagger UI's `SwaggerUIConfig::urls`, RapiDoc's `<rapi-doc>` element only
+    /// has a single `spec-url` attribute, so only the **first entry** is ever shown on the
+    /// rendered page; any further entries are reachable at their `url` (those registered with
+    /// `UrlObject::with_spec` via [rapidoc_spec](crate::rapidoc::rapidoc_spec)) but have no UI to
+    /// select them from. Keep this to one entry unless you're linking to the
+    /// others yourself.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub spec_urls: Vec<UrlObject>,
+    /// Text show in the top left corner of the page.
+    /// Default: `""` (RapiDoc falls back to the spec title).
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub heading_text: String,
+}
+
+/// Controls which parts of the default RapiDoc layout are shown or hidden.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HideShowConfig {
+    /// Shows/hides the documents header area; hiding it is useful when embedding RapiDoc into
+    /// another page.
+    /// Default: `false`.
+    pub show_header: bool,
+    /// Shows/hides the API info section.
+    /// Default: `true`.
+    pub show_info: bool,
+    /// Shows/hides the authentication section.
+    /// Default: `true`.
+    pub allow_authentication: bool,
+    /// Allow/disallow users from changing the server.
+    /// Default: `true`.
+    pub allow_server_selection: bool,
+    /// Allow/disallow users from loading another spec url.
+    /// Default: `true`.
+    pub allow_spec_url_load: bool,
+    /// Allow/disallow users from loading a spec file from disk.
+    /// Default: `true`.
+    pub allow_spec_file_load: bool,
+    /// Allow/disallow the global search box.
+    /// Default: `true`.
+    pub allow_search: bool,
+    /// Allow/disallow the "Try it out" feature for all the APIs.
+    /// Default: `true`.
+    pub allow_try: bool,
+}
+
+/// RapiDoc's color theme.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RapiDocTheme {
+    /// The light theme.
+    Light,
+    /// The dark theme.
+    Dark,
+}
+
+impl RapiDocTheme {
+    fn as_attribute(&self) -> &'static str {
+        match self {
+            Self::Light => "light",
+            Self::Dark => "dark",
+        }
+    }
+}
+
+/// Controls RapiDoc's look and feel.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UiConfig {
+    /// The color theme.
+    /// Default: `RapiDocTheme::Light`.
+    pub theme: RapiDocTheme,
+    /// The background color of the page.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub bg_color: String,
+    /// The text color of the page.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub text_color: String,
+    /// The color used for primary buttons/links.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub primary_color: String,
+}
+
+/// How the page itself is laid out.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Layout {
+    /// The API navigation and the reference are shown side by side.
+    Row,
+    /// The API navigation and the reference are stacked.
+    Column,
+}
+
+impl Layout {
+    fn as_attribute(&self) -> &'static str {
+        match self {
+            Self::Row => "row",
+            Self::Column => "column",
+        }
+    }
+}
+
+/// Controls how the operations/schemas are rendered.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RenderStyle {
+    /// A read focused tree view.
+    View,
+    /// A single operation focused view.
+    Focused,
+}
+
+impl RenderStyle {
+    fn as_attribute(&self) -> &'static str {
+        match self {
+            Self::View => "view",
+            Self::Focused => "focused",
+        }
+    }
+}
+
+/// Controls how schemas are rendered.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SchemaStyle {
+    /// Schemas are rendered as a tree.
+    Tree,
+    /// Schemas are rendered as a table.
+    Table,
+}
+
+impl SchemaStyle {
+    fn as_attribute(&self) -> &'static str {
+        match self {
+            Self::Tree => "tree",
+            Self::Table => "table",
+        }
+    }
+}
+
+/// Controls how the page and the API reference are laid out.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LayoutConfig {
+    /// The layout of the page.
+    /// Default: `Layout::Row`.
+    pub layout: Layout,
+    /// Controls how the operations/schemas are rendered.
+    /// Default: `RenderStyle::View`.
+    pub render_style: RenderStyle,
+    /// Controls how schemas are rendered.
+    /// Default: `SchemaStyle::Tree`.
+    pub schema_style: SchemaStyle,
+}
+
+/// A struct containing information about where and how RapiDoc is configured. Analogous to
+/// [`SwaggerUIConfig`](crate::swagger_ui::SwaggerUIConfig), but for the RapiDoc UI.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RapiDocConfig {
+    /// Which specs RapiDoc knows about.
+    #[serde(flatten)]
+    pub general: GeneralConfig,
+    /// Which parts of the UI are shown or hidden.
+    #[serde(flatten)]
+    pub hide_show: HideShowConfig,
+    /// RapiDoc's look and feel.
+    #[serde(flatten)]
+    pub ui: UiConfig,
+    /// How the page is laid out.
+    #[serde(flatten)]
+    pub layout: LayoutConfig,
+}
+
+impl Default for GeneralConfig {
+    fn default() -> Self {
+        Self {
+            spec_urls: vec![],
+            heading_text: String::new(),
+        }
+    }
+}
+
+impl Default for HideShowConfig {
+    fn default() -> Self {
+        Self {
+            show_header: false,
+            show_info: true,
+            allow_authentication: true,
+            allow_server_selection: true,
+            allow_spec_url_load: true,
+            allow_spec_file_load: true,
+            allow_search: true,
+            allow_try: true,
+        }
+    }
+}
+
+impl Default for UiConfig {
+    fn default() -> Self {
+        Self {
+            theme: RapiDocTheme::Light,
+            bg_color: String::new(),
+            text_color: String::new(),
+            primary_color: String::new(),
+        }
+    }
+}
+
+impl Default for LayoutConfig {
+    fn default() -> Self {
+        Self {
+            layout: Layout::Row,
+            render_style: RenderStyle::View,
+            schema_style: SchemaStyle::Tree,
+        }
+    }
+}
+
+impl Default for RapiDocConfig {
+    fn default() -> Self {
+        Self {
+            general: GeneralConfig::default(),
+            hide_show: HideShowConfig::default(),
+            ui: UiConfig::default(),
+            layout: LayoutConfig::default(),
+        }
+    }
+}
+
+impl RapiDocConfig {
+    /// Fairing for managing this RapiDoc configuration
+    pub fn fairing(self) -> impl rocket::fairing::Fairing {
+        rocket::fairing::AdHoc::try_on_ignite("RapiDocConfig", move |rocket| async move {
+            Ok(rocket.manage(self))
+        })
+    }
+
+    /// The `spec-url` attribute of the `<rapi-doc>` element. RapiDoc only ever shows one spec,
+    /// so this is always the **first** entry of [`GeneralConfig::spec_urls`] (or an empty string
+    /// if none was configured) — any further entries are not reachable from the page itself.
+    fn spec_url(&self) -> &str {
+        self.general
+            .spec_urls
+            .first()
+            .map(|url| url.url.as_str())
+            .unwrap_or_default()
+    }
+
+    /// Renders the attributes of the bundled `<rapi-doc>` element from this configuration.
+    pub(crate) fn render_attributes(&self) -> String {
+        format!(
+            concat!(
+                "spec-url=\"{spec_url}\" heading-text=\"{heading_text}\" ",
+                "show-header=\"{show_header}\" show-info=\"{show_info}\" ",
+                "allow-authentication=\"{allow_authentication}\" ",
+                "allow-server-selection=\"{allow_server_selection}\" ",
+                "allow-spec-url-load=\"{allow_spec_url_load}\" ",
+                "allow-spec-file-load=\"{allow_spec_file_load}\" ",
+                "allow-search=\"{allow_search}\" allow-try=\"{allow_try}\" ",
+                "theme=\"{theme}\" bg-color=\"{bg_color}\" text-color=\"{text_color}\" ",
+                "primary-color=\"{primary_color}\" layout=\"{layout}\" ",
+                "render-style=\"{render_style}\" schema-style=\"{schema_style}\"",
+            ),
+            spec_url = escape_attribute(self.spec_url()),
+            heading_text = escape_attribute(&self.general.heading_text),
+            show_header = self.hide_show.show_header,
+            show_info = self.hide_show.show_info,
+            allow_authentication = self.hide_show.allow_authentication,
+            allow_server_selection = self.hide_show.allow_server_selection,
+            allow_spec_url_load = self.hide_show.allow_spec_url_load,
+            allow_spec_file_load = self.hide_show.allow_spec_file_load,
+            allow_search = self.hide_show.allow_search,
+            allow_try = self.hide_show.allow_try,
+            theme = self.ui.theme.as_attribute(),
+            bg_color = escape_attribute(&self.ui.bg_color),
+            text_color = escape_attribute(&self.ui.text_color),
+            primary_color = escape_attribute(&self.ui.primary_color),
+            layout = self.layout.layout.as_attribute(),
+            render_style = self.layout.render_style.as_attribute(),
+            schema_style = self.layout.schema_style.as_attribute(),
+        )
+    }
+}
+
+/// Escapes `&`, `<` and `"` so that `value` can be safely interpolated into a double-quoted HTML
+/// attribute.
+fn escape_attribute(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('"', "&quot;")
+}
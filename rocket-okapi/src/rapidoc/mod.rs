@@ -0,0 +1,92 @@
+use crate::handlers::RedirectHandler;
+
+use rocket::get;
+use rocket::http::ContentType;
+use rocket::response::content::RawHtml;
+use rocket::serde::json::Json;
+
+mod config;
+
+pub use config::{GeneralConfig, HideShowConfig, LayoutConfig, RapiDocConfig, UiConfig};
+
+const RAPIDOC_INDEX: &str = include_str!("../../rapidoc-ui/index.html");
+
+macro_rules! rapidoc_static_files {
+    ($file:ident, $($name:literal => $type:ident),*) => (
+        match $file {
+            $(
+                $name => Some(
+                    (ContentType::$type, include_bytes!(concat!("../../rapidoc-ui/", $name)))
+                ),
+            )*
+            _ => None
+        }
+    );
+}
+
+/// Route for RapiDoc configuration file
+#[get("/rapidoc-config.json")]
+pub fn rapidoc_config(config: &rocket::State<RapiDocConfig>) -> Json<&RapiDocConfig> {
+    Json(config.inner())
+}
+
+/// Route for the RapiDoc `index.html`. Unlike the rest of the bundled files, this one is not
+/// served verbatim: the attributes of its `<rapi-doc>` element are templated from the managed
+/// [`RapiDocConfig`] so that e.g. `spec-url` and `theme` reach the page without a separate
+/// round-trip to `rapidoc-config.json`.
+#[get("/index.html")]
+pub fn rapidoc_index(config: &rocket::State<RapiDocConfig>) -> RawHtml<String> {
+    RawHtml(RAPIDOC_INDEX.replace("{{attributes}}", &config.render_attributes()))
+}
+
+/// Route for any `OpenApi` document registered through [UrlObject::with_spec](crate::swagger_ui::UrlObject::with_spec)
+/// in [GeneralConfig::spec_urls]. For every other file name it falls back to the bundled
+/// [rapidoc_static] assets.
+#[get("/<name>", rank = 1)]
+pub fn rapidoc_spec<'r>(
+    name: &str,
+    config: &'r rocket::State<RapiDocConfig>,
+) -> Option<(ContentType, Vec<u8>)> {
+    let spec = config
+        .general
+        .spec_urls
+        .iter()
+        .find(|url| url.url == name)
+        .and_then(|url| url.spec.as_ref());
+    // Returning `None` is a 404, not a forward to [rapidoc_static], so fall back here.
+    match spec {
+        Some(spec) => Some((ContentType::JSON, serde_json::to_vec(spec).ok()?)),
+        None => rapidoc_static(name).map(|(content_type, data)| (content_type, data.to_vec())),
+    }
+}
+
+/// Route for RapiDoc static files
+#[get("/<file>", rank = 2)]
+pub fn rapidoc_static(file: &str) -> Option<(ContentType, &'static [u8])> {
+    rapidoc_static_files!(file,
+        "rapidoc-min.js" => JavaScript
+    )
+}
+
+/// Redirects `/<rapidoc-base>/` to `/<rapidoc-base>/index.html`
+#[get("/")]
+pub fn rapidoc_redirect<'r>() -> RedirectHandler<'r> {
+    RedirectHandler::to("index.html")
+}
+
+/// Create Rocket routes for RapiDoc. Note that only the first entry of
+/// [GeneralConfig::spec_urls](crate::rapidoc::GeneralConfig::spec_urls) is ever shown on the
+/// rendered page; further entries registered with `UrlObject::with_spec` are served (by
+/// [rapidoc_spec]) but have no UI to select them from.
+#[macro_export]
+macro_rules! rapidoc_routes {
+    [] => {
+        rocket::routes![
+            rocket_okapi::rapidoc::rapidoc_config,
+            rocket_okapi::rapidoc::rapidoc_index,
+            rocket_okapi::rapidoc::rapidoc_spec,
+            rocket_okapi::rapidoc::rapidoc_static,
+            rocket_okapi::rapidoc::rapidoc_redirect,
+        ]
+    };
+}
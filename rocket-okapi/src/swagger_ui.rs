@@ -1,9 +1,11 @@
-use crate::handlers::RedirectHandler;
+use crate::handlers::{ContentHandler, RedirectHandler};
+use okapi::openapi3::OpenApi;
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 
 use rocket::fairing::{AdHoc, Fairing};
 use rocket::serde::json::Json;
-use rocket::http::{ContentType, Status};
+use rocket::http::ContentType;
 use rocket::get;
 
 
@@ -43,6 +45,46 @@ fn is_zero(num: &u32) -> bool {
     *num == 0
 }
 
+/// The color theme used to highlight code/response bodies, or `false` to disable syntax
+/// highlighting altogether (useful for very large responses, where highlighting can be slow).
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum SyntaxHighlight {
+    /// Enable or disable syntax highlighting, keeping the default theme.
+    Bool(bool),
+    /// Enable syntax highlighting with the given theme. Serializes as the `{ activated, theme }`
+    /// object Swagger UI's `syntaxHighlight` option expects; construct with [SyntaxHighlight::theme].
+    Config {
+        /// Always `true`; see [SyntaxHighlight::theme].
+        activated: bool,
+        /// The highlighting theme to use.
+        theme: SyntaxHighlightTheme,
+    },
+}
+
+impl SyntaxHighlight {
+    /// Enable syntax highlighting with the given `theme`.
+    #[must_use]
+    pub fn theme(theme: SyntaxHighlightTheme) -> Self {
+        Self::Config {
+            activated: true,
+            theme,
+        }
+    }
+}
+
+/// A syntax highlighting theme bundled with Swagger UI.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SyntaxHighlightTheme {
+    /// The `agate` theme.
+    Agate,
+    /// The `monokai` theme.
+    Monokai,
+    /// The `nord` theme.
+    Nord,
+}
+
 /// A struct containing information about where and how the `openapi.json` files are served.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -97,6 +139,33 @@ pub struct SwaggerUIConfig {
     /// `minimum`) fields and values for Parameters.
     /// Default: `false`.
     pub show_common_extensions: bool,
+    /// Pre-configures Swagger UI's "Authorize" flow for OAuth2/OIDC protected endpoints by
+    /// calling `ui.initOAuth(...)` with these settings once the page has loaded.
+    /// Default: `None` (OAuth2 is not pre-configured).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oauth: Option<OAuthConfig>,
+    /// If set to true, authorization data (e.g. entered API keys or OAuth tokens) is persisted
+    /// in `localStorage` and survives a page reload.
+    /// Default: `false`.
+    pub persist_authorization: bool,
+    /// Controls whether the "Try it out" feature is enabled by default, without the user having
+    /// to first click the "Try it out" button.
+    /// Default: `false`.
+    pub try_it_out_enabled: bool,
+    /// Controls the display of curl/request snippets for "Try it out" requests.
+    /// Default: `false`.
+    pub request_snippets_enabled: bool,
+    /// Restricts the set of HTTP methods that "Try it out" is enabled for. Pass an empty `Vec`
+    /// to disable "Try it out" for every method.
+    /// Default: `["get", "put", "post", "delete", "options", "head", "patch", "trace"]`.
+    pub supported_submit_methods: Vec<String>,
+    /// Controls whether "Try it out" requests are sent with credentials (cookies, HTTP
+    /// authentication), i.e. the `fetch` `credentials` option set to `same-origin`.
+    /// Default: `false`.
+    pub with_credentials: bool,
+    /// Controls syntax highlighting of code/response bodies, or disables it entirely.
+    /// Default: `SyntaxHighlight::Bool(true)`.
+    pub syntax_highlight: SyntaxHighlight,
 }
 
 impl Default for SwaggerUIConfig {
@@ -115,6 +184,74 @@ impl Default for SwaggerUIConfig {
             max_displayed_tags: 0,
             show_extensions: false,
             show_common_extensions: false,
+            oauth: None,
+            persist_authorization: false,
+            try_it_out_enabled: false,
+            request_snippets_enabled: false,
+            supported_submit_methods: [
+                "get", "put", "post", "delete", "options", "head", "patch", "trace",
+            ]
+            .into_iter()
+            .map(String::from)
+            .collect(),
+            with_credentials: false,
+            syntax_highlight: SyntaxHighlight::Bool(true),
+        }
+    }
+}
+
+/// Settings passed to Swagger UI's [`ui.initOAuth(...)`](https://swagger.io/docs/open-source-tools/swagger-ui/usage/oauth2/)
+/// call, used to pre-configure the "Authorize" flow for OAuth2/OIDC protected endpoints.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OAuthConfig {
+    /// The client id for your application.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub client_id: String,
+    /// The client secret for your application. Only required for the `implicit` and
+    /// `accessCode` flows, and only recommended for use during development, as it will be
+    /// visible to anyone inspecting the page.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub client_secret: String,
+    /// The realm to pass to the OAuth2/OIDC authorization server, used for example by Keycloak.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub realm: String,
+    /// The name to register the application with to the OAuth2/OIDC authorization server.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub app_name: String,
+    /// The separator used to join multiple scopes when they're sent to the authorization
+    /// server.
+    /// Default: `" "`.
+    pub scope_separator: String,
+    /// The scopes to request when obtaining an authorization code. Default: `[]`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub scopes: Vec<String>,
+    /// Additional query parameters added to the authorization request.
+    /// Default: `{}`.
+    #[serde(default, skip_serializing_if = "Map::is_empty")]
+    pub additional_query_string_params: Map<String, Value>,
+    /// Only activated for the `accessCode` flow. During the `authorization_code` request to the
+    /// token endpoint, the client ID/secret are sent using HTTP basic authentication rather than
+    /// in the request body.
+    /// Default: `false`.
+    pub use_basic_authentication_with_access_code_grant: bool,
+    /// Whether to use PKCE with the `authorizationCode` grant type.
+    /// Default: `false`.
+    pub use_pkce_with_authorization_code_grant: bool,
+}
+
+impl Default for OAuthConfig {
+    fn default() -> Self {
+        Self {
+            client_id: String::new(),
+            client_secret: String::new(),
+            realm: String::new(),
+            app_name: String::new(),
+            scope_separator: " ".to_owned(),
+            scopes: vec![],
+            additional_query_string_params: Map::new(),
+            use_basic_authentication_with_access_code_grant: false,
+            use_pkce_with_authorization_code_grant: false,
         }
     }
 }
@@ -135,6 +272,11 @@ pub struct UrlObject {
     pub name: String,
     /// The url itself.
     pub url: String,
+    /// An `OpenApi` document to serve at [UrlObject::url] ourselves, so that `url` never has to
+    /// point outside of wherever [swagger_ui_routes!] is mounted. Populated by
+    /// [UrlObject::with_spec]; not part of the JSON sent to the Swagger UI front-end.
+    #[serde(skip)]
+    pub spec: Option<OpenApi>,
 }
 
 impl UrlObject {
@@ -144,6 +286,20 @@ impl UrlObject {
         Self {
             name: name.to_string(),
             url: url.to_string(),
+            spec: None,
+        }
+    }
+
+    /// Create a new `UrlObject` that also serves its own `OpenApi` document. Combined with
+    /// [swagger_ui_routes!], this makes `url` (relative to the mount point) resolve to `spec`
+    /// rather than requiring it to be mounted separately, so the UI and the documents it points
+    /// at can never drift out of sync.
+    #[must_use]
+    pub fn with_spec(name: &str, url: &str, spec: OpenApi) -> Self {
+        Self {
+            name: name.to_string(),
+            url: url.to_string(),
+            spec: Some(spec),
         }
     }
 }
@@ -152,12 +308,11 @@ macro_rules! swagger_static_files {
     ($file:ident, $($name:literal => $type:ident),*) => (
         match $file {
             $(
-                $name => (
-                    Status::Ok,
+                $name => Some(
                     (ContentType::$type, include_bytes!(concat!("../swagger-ui/", $name)))
                 ),
             )*
-            _ => (Status::NotFound, (ContentType::Plain, &[]))
+            _ => None
         }
     );
 }
@@ -171,11 +326,75 @@ pub fn swagger_ui_config(
     Json(config.inner())
 }
 
+/// Route for any `OpenApi` document registered through [UrlObject::with_spec]. For every other
+/// file name it falls back to [swagger_ui_extra] and then to the bundled [swagger_ui_static] assets.
+#[get("/<name>", rank = 1)]
+pub fn swagger_ui_spec<'r>(
+    name: &str,
+    config: &'r rocket::State<SwaggerUIConfig>,
+    extra: Option<&'r rocket::State<SwaggerUIExtraFiles>>,
+) -> Option<ContentHandler> {
+    let spec = config
+        .urls
+        .iter()
+        .find(|url| url.url == name)
+        .and_then(|url| url.spec.as_ref());
+    // Returning `None` is a 404, not a forward to the lower-ranked routes, so fall back here.
+    match spec {
+        Some(spec) => Some(ContentHandler::bytes(ContentType::JSON, serde_json::to_vec(spec).ok()?)),
+        None => swagger_ui_extra(name, extra)
+            .or_else(|| swagger_ui_static(name).map(|(ct, data)| ContentHandler::bytes(ct, data.to_vec()))),
+    }
+}
+
+/// Extra files registered into the Swagger UI route set, e.g. a custom `swagger-ui.css` theme
+/// override or the spec served as `openapi.yaml`. Attach with [SwaggerUIExtraFiles::fairing];
+/// if it isn't attached, only the bundled [swagger_ui_static] assets are served.
+#[derive(Debug, Clone, Default)]
+pub struct SwaggerUIExtraFiles(Vec<(String, ContentHandler)>);
+
+impl SwaggerUIExtraFiles {
+    /// Create a new, empty set of extra files.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `name` (e.g. `"swagger-ui.css"` or `"openapi.yaml"`) to be served by `handler`.
+    #[must_use]
+    pub fn with(mut self, name: &str, handler: ContentHandler) -> Self {
+        self.0.push((name.to_string(), handler));
+        self
+    }
+
+    /// Fairing for managing this set of extra files.
+    pub fn fairing(self) -> impl Fairing {
+        AdHoc::try_on_ignite("SwaggerUIExtraFiles", move |rocket| async move {
+            Ok(rocket.manage(self))
+        })
+    }
+}
+
+/// Route for extra files registered through [SwaggerUIExtraFiles]. Tried after [swagger_ui_spec]
+/// but before the bundled [swagger_ui_static] assets, so an extra file can also override one of
+/// the bundled ones (e.g. a custom `swagger-ui.css`).
+#[get("/<file>", rank = 2)]
+pub fn swagger_ui_extra(
+    file: &str,
+    extra: Option<&rocket::State<SwaggerUIExtraFiles>>,
+) -> Option<ContentHandler> {
+    extra?
+        .0
+        .iter()
+        .find(|(name, _)| name == file)
+        .map(|(_, handler)| handler.clone())
+}
+
 /// Route for Swagger static files
-#[get("/<file>")]
+#[get("/<file>", rank = 3)]
 pub fn swagger_ui_static(
     file: &str,
-) -> (Status, (ContentType, &'static [u8])) {
+) -> Option<(ContentType, &'static [u8])> {
     swagger_static_files!(file,
         "favicon-16x16.png"               => PNG,
         "favicon-32x32.png"               => PNG,
@@ -200,6 +419,8 @@ macro_rules! swagger_ui_routes {
     [] => {
         rocket::routes![
             rocket_okapi::swagger_ui::swagger_ui_config,
+            rocket_okapi::swagger_ui::swagger_ui_spec,
+            rocket_okapi::swagger_ui::swagger_ui_extra,
             rocket_okapi::swagger_ui::swagger_ui_static,
             rocket_okapi::swagger_ui::swagger_ui_redirect,
         ]